@@ -0,0 +1,257 @@
+//! A command-line front end for the Shadow Drive client.
+//!
+//! The subcommands mirror the library surface (`create-storage-account`,
+//! `add-storage`, `store-files`, …) so the crate can be driven from a shell
+//! without writing any Rust. The structure follows Solana's
+//! `cli/src/storage.rs`: a [`StorageSubCommands`] trait extends the `clap`
+//! [`Command`] with the storage subcommands, and [`main`] dispatches the parsed
+//! matches against a [`ShadowDriveClient`].
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use byte_unit::Byte;
+use clap::{Arg, ArgMatches, Command};
+use shadow_drive_rust::models::ShadowFile;
+use shadow_drive_rust::ShadowDriveClient;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::keypair::read_keypair_file;
+
+/// Extends [`Command`] with the Shadow Drive storage subcommands.
+///
+/// Implemented for [`Command`] so the subcommands can be attached with a single
+/// `.storage_subcommands()` call, matching the `StorageSubCommands` pattern used
+/// by the Solana CLI.
+pub trait StorageSubCommands {
+    /// Attaches every storage subcommand to the command.
+    fn storage_subcommands(self) -> Self;
+}
+
+impl StorageSubCommands for Command {
+    fn storage_subcommands(self) -> Self {
+        self.subcommand(
+            Command::new("create-storage-account")
+                .about("Create a new storage account")
+                .arg(name_arg())
+                .arg(size_arg()),
+        )
+        .subcommand(
+            Command::new("add-storage")
+                .about("Add storage capacity to an existing storage account")
+                .arg(storage_account_arg())
+                .arg(size_arg()),
+        )
+        .subcommand(
+            Command::new("reduce-storage")
+                .about("Reduce the storage capacity of a storage account")
+                .arg(storage_account_arg())
+                .arg(size_arg()),
+        )
+        .subcommand(
+            Command::new("make-immutable")
+                .about("Permanently mark a storage account immutable")
+                .arg(storage_account_arg()),
+        )
+        .subcommand(
+            Command::new("store-files")
+                .about("Upload one or more files to a storage account")
+                .arg(storage_account_arg())
+                .arg(
+                    Arg::new("files")
+                        .long("file")
+                        .value_name("PATH")
+                        .num_args(1..)
+                        .action(clap::ArgAction::Append)
+                        .required(true)
+                        .value_parser(is_existing_file)
+                        .help("Path of a file to upload (repeat for multiple files)"),
+                ),
+        )
+        .subcommand(
+            Command::new("delete-file")
+                .about("Delete a single stored file")
+                .arg(storage_account_arg())
+                .arg(
+                    Arg::new("file-url")
+                        .value_name("URL")
+                        .required(true)
+                        .help("The URL of the file to delete"),
+                ),
+        )
+        .subcommand(
+            Command::new("list-objects")
+                .about("List the objects stored under a storage account")
+                .arg(storage_account_arg()),
+        )
+        .subcommand(
+            Command::new("get-storage-account")
+                .about("Print the details of a storage account")
+                .arg(storage_account_arg()),
+        )
+    }
+}
+
+fn keypair_arg() -> Arg {
+    Arg::new("keypair")
+        .long("keypair")
+        .short('k')
+        .value_name("PATH")
+        .global(true)
+        .value_parser(is_valid_signer)
+        .help("Path to the signing keypair file [default: ~/.config/solana/id.json]")
+}
+
+fn rpc_url_arg() -> Arg {
+    Arg::new("url")
+        .long("url")
+        .short('u')
+        .value_name("URL")
+        .global(true)
+        .default_value("https://ssc-dao.genesysgo.net")
+        .help("The Solana RPC endpoint to connect to")
+}
+
+fn storage_account_arg() -> Arg {
+    Arg::new("storage-account")
+        .value_name("STORAGE_ACCOUNT")
+        .required(true)
+        .value_parser(is_pubkey)
+        .help("The public key of the storage account")
+}
+
+fn name_arg() -> Arg {
+    Arg::new("name")
+        .long("name")
+        .value_name("NAME")
+        .required(true)
+        .help("A human-readable name for the storage account")
+}
+
+fn size_arg() -> Arg {
+    Arg::new("size")
+        .value_name("SIZE")
+        .required(true)
+        .value_parser(is_byte_size)
+        .help("Storage amount, e.g. 1KB, 10MB, 1GB")
+}
+
+// Argument validators, mirroring Solana's `is_pubkey` / `is_valid_signer` helpers.
+
+fn is_pubkey(value: &str) -> Result<Pubkey, String> {
+    Pubkey::from_str(value).map_err(|_| format!("{} is not a valid pubkey", value))
+}
+
+fn is_valid_signer(value: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(value);
+    if path.exists() {
+        Ok(path)
+    } else {
+        Err(format!("keypair file {} does not exist", value))
+    }
+}
+
+fn is_existing_file(value: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(value);
+    if path.is_file() {
+        Ok(path)
+    } else {
+        Err(format!("file {} does not exist", value))
+    }
+}
+
+fn is_byte_size(value: &str) -> Result<Byte, String> {
+    Byte::from_str(value).map_err(|err| format!("invalid storage size {}: {}", value, err))
+}
+
+fn default_keypair_path() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_default()
+        .join(".config/solana/id.json")
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let matches = Command::new("shadow-drive")
+        .about("Interact with the Shadow Drive from the command line")
+        .version(env!("CARGO_PKG_VERSION"))
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .arg(keypair_arg())
+        .arg(rpc_url_arg())
+        .storage_subcommands()
+        .get_matches();
+
+    let keypair_path = matches
+        .get_one::<PathBuf>("keypair")
+        .cloned()
+        .unwrap_or_else(default_keypair_path);
+    let wallet = read_keypair_file(&keypair_path)
+        .map_err(|err| anyhow::anyhow!("failed to read keypair {:?}: {}", keypair_path, err))?;
+    let rpc_url = matches.get_one::<String>("url").unwrap().clone();
+
+    let client = ShadowDriveClient::new(wallet, rpc_url);
+
+    match matches.subcommand() {
+        Some(("create-storage-account", sub)) => {
+            let name = sub.get_one::<String>("name").unwrap();
+            let size = sub.get_one::<Byte>("size").unwrap();
+            let response = client.create_storage_account(name, *size).await?;
+            println!("{:#?}", response);
+        }
+        Some(("add-storage", sub)) => {
+            let key = sub.get_one::<Pubkey>("storage-account").unwrap();
+            let size = sub.get_one::<Byte>("size").unwrap();
+            let response = client.add_storage(key, *size).await?;
+            println!("{:#?}", response);
+        }
+        Some(("reduce-storage", sub)) => {
+            let key = sub.get_one::<Pubkey>("storage-account").unwrap();
+            let size = sub.get_one::<Byte>("size").unwrap();
+            let response = client.reduce_storage(key, *size).await?;
+            println!("{:#?}", response);
+        }
+        Some(("make-immutable", sub)) => {
+            let key = sub.get_one::<Pubkey>("storage-account").unwrap();
+            let response = client.make_storage_immutable(key).await?;
+            println!("{:#?}", response);
+        }
+        Some(("store-files", sub)) => {
+            let key = sub.get_one::<Pubkey>("storage-account").unwrap();
+            let files = sub
+                .get_many::<PathBuf>("files")
+                .unwrap()
+                .map(|path| {
+                    let name = path
+                        .file_name()
+                        .and_then(|n| n.to_str())
+                        .ok_or_else(|| anyhow::anyhow!("invalid file name: {:?}", path))?
+                        .to_string();
+                    Ok(ShadowFile::file(name, path.clone()))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            let response = client.store_files(key, files).await?;
+            println!("{:#?}", response);
+        }
+        Some(("delete-file", sub)) => {
+            let key = sub.get_one::<Pubkey>("storage-account").unwrap();
+            let url = sub.get_one::<String>("file-url").unwrap();
+            let response = client.delete_file(key, url.clone()).await?;
+            println!("{:#?}", response);
+        }
+        Some(("list-objects", sub)) => {
+            let key = sub.get_one::<Pubkey>("storage-account").unwrap();
+            let objects = client.list_objects(key).await?;
+            for object in objects {
+                println!("{}", object);
+            }
+        }
+        Some(("get-storage-account", sub)) => {
+            let key = sub.get_one::<Pubkey>("storage-account").unwrap();
+            let account = client.get_storage_account(key).await?;
+            println!("{:#?}", account);
+        }
+        _ => unreachable!("subcommand_required guarantees a subcommand"),
+    }
+
+    Ok(())
+}
@@ -1,12 +1,18 @@
 use std::time::Duration;
 
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
 use serde_json::{json, Value};
-use solana_client::nonblocking::rpc_client::RpcClient;
+use shadow_drive_auth::{authenticate, parse_account_id_from_url};
+use solana_client::{
+    http_sender::HttpSender, nonblocking::rpc_client::RpcClient, rpc_client::RpcClientConfig,
+};
 use solana_sdk::{commitment_config::CommitmentConfig, signer::Signer, transaction::Transaction};
 
 mod add_storage;
 mod cancel_delete_storage_account;
+mod compression;
 mod claim_stake;
+mod claimable_stake;
 mod create_storage_account;
 mod delete_file;
 mod delete_storage_account;
@@ -16,11 +22,14 @@ mod list_objects;
 mod make_storage_immutable;
 mod reduce_storage;
 mod store_files;
+mod store_files_batch;
 // mod upload_multiple_files;
 
 pub use add_storage::*;
 pub use cancel_delete_storage_account::*;
+pub use compression::*;
 pub use claim_stake::*;
+pub use claimable_stake::*;
 pub use create_storage_account::*;
 pub use delete_file::*;
 pub use delete_storage_account::*;
@@ -30,6 +39,7 @@ pub use list_objects::*;
 pub use make_storage_immutable::*;
 pub use reduce_storage::*;
 pub use store_files::*;
+pub use store_files_batch::*;
 
 use crate::{
     constants::SHDW_DRIVE_ENDPOINT,
@@ -45,6 +55,7 @@ where
     wallet: T,
     rpc_client: RpcClient,
     http_client: reqwest::Client,
+    auth_token: Option<String>,
 }
 
 impl<T> ShadowDriveClient<T>
@@ -78,6 +89,7 @@ where
             wallet,
             rpc_client,
             http_client: reqwest::Client::new(),
+            auth_token: None,
         }
     }
 
@@ -102,9 +114,65 @@ where
             wallet,
             rpc_client,
             http_client: reqwest::Client::new(),
+            auth_token: None,
         }
     }
 
+    /// Creates a new [`ShadowDriveClient`] that authenticates against a premium
+    /// GenesysGo RPC endpoint before issuing any requests.
+    /// * `wallet` - A [`Signer`] used both to sign the GenesysGo sign-in message and to sign transactions.
+    /// * `rpc_url` - The premium GenesysGo RPC URL. The account id is parsed from this URL via [`parse_account_id_from_url`].
+    ///
+    /// This runs the two-step GenesysGo sign-in ([`authenticate`]) and configures
+    /// both the storage-API [`reqwest::Client`] and the Solana [`RpcClient`] to send
+    /// the resulting JWT as an `Authorization: Bearer` header on every subsequent
+    /// request, so on-chain RPC calls hit the premium endpoint authenticated. Use
+    /// [`refresh_token`](Self::refresh_token) to re-authenticate when the token expires.
+    pub async fn new_authenticated<U: ToString>(wallet: T, rpc_url: U) -> ShadowDriveResult<Self> {
+        let rpc_url = rpc_url.to_string();
+        let account_id = parse_account_id_from_url(rpc_url.clone())
+            .map_err(|error| Error::AuthenticationFailed(error.to_string()))?;
+        let token = authenticate(&wallet, &account_id)
+            .await
+            .map_err(|error| Error::AuthenticationFailed(error.to_string()))?;
+
+        let http_client = authenticated_http_client(&token)?;
+        let rpc_client = authenticated_rpc_client(rpc_url, http_client.clone());
+
+        Ok(Self {
+            wallet,
+            rpc_client,
+            http_client,
+            auth_token: Some(token),
+        })
+    }
+
+    /// Re-runs the GenesysGo sign-in and swaps in a freshly issued bearer token on
+    /// both the storage-API client and the Solana [`RpcClient`].
+    ///
+    /// Call this when the current token has expired; the RPC endpoint is preserved.
+    pub async fn refresh_token(&mut self) -> ShadowDriveResult<()> {
+        let rpc_url = self.rpc_client.url();
+        let account_id = parse_account_id_from_url(rpc_url.clone())
+            .map_err(|error| Error::AuthenticationFailed(error.to_string()))?;
+        let token = authenticate(&self.wallet, &account_id)
+            .await
+            .map_err(|error| Error::AuthenticationFailed(error.to_string()))?;
+
+        let http_client = authenticated_http_client(&token)?;
+        self.rpc_client = authenticated_rpc_client(rpc_url, http_client.clone());
+        self.http_client = http_client;
+        self.auth_token = Some(token);
+        Ok(())
+    }
+
+    /// Fetches the stored object's metadata from the Shadow Drive API.
+    ///
+    /// This returns only the object's metadata ([`FileDataResponse`]); it does not
+    /// fetch or decompress the object's contents. To retrieve the contents, including
+    /// objects uploaded with [`store_files_compressed`](Self::store_files_compressed),
+    /// use [`download_object`](Self::download_object), which detects the
+    /// compression header and transparently returns the original bytes.
     pub async fn get_object_data(&self, location: &str) -> ShadowDriveResult<FileDataResponse> {
         let response = self
             .http_client
@@ -158,6 +226,32 @@ where
     }
 }
 
+/// Builds a [`reqwest::Client`] that sends `token` as an `Authorization: Bearer`
+/// header on every request.
+fn authenticated_http_client(token: &str) -> ShadowDriveResult<reqwest::Client> {
+    let mut headers = HeaderMap::new();
+    let mut value = HeaderValue::from_str(&format!("Bearer {}", token))
+        .map_err(|error| Error::AuthenticationFailed(error.to_string()))?;
+    value.set_sensitive(true);
+    headers.insert(AUTHORIZATION, value);
+
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(120))
+        .default_headers(headers)
+        .build()
+        .map_err(Error::from)
+}
+
+/// Builds an [`RpcClient`] that issues its JSON-RPC requests through `http_client`,
+/// so the bearer token configured on that client authenticates every RPC call.
+fn authenticated_rpc_client(rpc_url: String, http_client: reqwest::Client) -> RpcClient {
+    let sender = HttpSender::new_with_client(rpc_url, http_client);
+    RpcClient::new_sender(
+        sender,
+        RpcClientConfig::with_commitment(CommitmentConfig::finalized()),
+    )
+}
+
 pub(crate) fn serialize_and_encode(txn: &Transaction) -> ShadowDriveResult<String> {
     let serialized = bincode::serialize(txn)
         .map_err(|error| Error::TransactionSerializationFailed(format!("{:?}", error)))?;
@@ -0,0 +1,102 @@
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    rpc_request::RpcError,
+};
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+use super::ShadowDriveClient;
+use crate::{
+    constants::TOKEN_MINT,
+    derived_addresses,
+    error::Error,
+    models::{ShadowDriveResult, ShdwDriveResponse},
+};
+
+/// The result of attempting to claim stake for a single storage account during
+/// a [`claim_all_stake`](ShadowDriveClient::claim_all_stake) batch.
+pub struct ClaimStakeResult {
+    /// The storage account this result corresponds to.
+    pub storage_account_key: Pubkey,
+    /// The claimable amount, in SHDW base units, observed before claiming.
+    pub claimable: u64,
+    /// The outcome of the claim, or `None` when the account had nothing to claim
+    /// and was skipped.
+    pub result: Option<ShadowDriveResult<ShdwDriveResponse>>,
+}
+
+impl<T> ShadowDriveClient<T>
+where
+    T: Signer + Send + Sync,
+{
+    /// Returns the SHDW stake reward currently claimable for the given
+    /// [`StorageAccount`](crate::models::StorageAccount), in SHDW base units.
+    ///
+    /// The emissions program deposits accrued rewards into the storage account's
+    /// stake token account; this reads that account's balance, which is the amount
+    /// a [`claim_stake`](ShadowDriveClient::claim_stake) would transfer to the
+    /// owner. A storage account whose stake account has never been funded has
+    /// nothing accrued yet, so a missing account is reported as `0` rather than an
+    /// error — letting callers decide whether a claim is worth the transaction fee.
+    pub async fn get_claimable_stake(
+        &self,
+        storage_account_key: &Pubkey,
+    ) -> ShadowDriveResult<u64> {
+        let (stake_account, _) = derived_addresses::stake_account(storage_account_key);
+        let stake_ata = get_associated_token_address(&stake_account, &TOKEN_MINT);
+
+        match self.rpc_client.get_token_account_balance(&stake_ata).await {
+            Ok(balance) => balance.amount.parse::<u64>().map_err(|_| Error::InvalidStorage),
+            // A token account that was never funded doesn't exist on-chain, so the
+            // RPC server rejects the balance query with "could not find account"
+            // (`-32602`). Treat that as nothing accrued rather than a hard failure.
+            Err(ClientError {
+                kind: ClientErrorKind::RpcError(RpcError::RpcResponseError { code, message, .. }),
+                ..
+            }) if code == -32602 || message.contains("could not find account") => Ok(0),
+            Err(err) => Err(Error::from(err)),
+        }
+    }
+
+    /// Claims stake rewards across many storage accounts in a resilient sequence.
+    ///
+    /// Each account's claimable amount is queried via
+    /// [`get_claimable_stake`](Self::get_claimable_stake) first; accounts with
+    /// nothing to claim are skipped (their [`ClaimStakeResult::result`] is `None`).
+    /// A failure on one account doesn't abort the rest — every account produces a
+    /// [`ClaimStakeResult`] in the returned vector.
+    pub async fn claim_all_stake(
+        &self,
+        storage_account_keys: &[Pubkey],
+    ) -> Vec<ClaimStakeResult> {
+        let mut results = Vec::with_capacity(storage_account_keys.len());
+
+        for storage_account_key in storage_account_keys {
+            let claimable = match self.get_claimable_stake(storage_account_key).await {
+                Ok(claimable) => claimable,
+                Err(error) => {
+                    results.push(ClaimStakeResult {
+                        storage_account_key: *storage_account_key,
+                        claimable: 0,
+                        result: Some(Err(error)),
+                    });
+                    continue;
+                }
+            };
+
+            let result = if claimable == 0 {
+                None
+            } else {
+                Some(self.claim_stake(storage_account_key).await)
+            };
+
+            results.push(ClaimStakeResult {
+                storage_account_key: *storage_account_key,
+                claimable,
+                result,
+            });
+        }
+
+        results
+    }
+}
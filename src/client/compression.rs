@@ -0,0 +1,190 @@
+use serde_json::Value;
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+
+use super::ShadowDriveClient;
+use crate::{
+    error::Error,
+    models::{ShadowDriveResult, ShadowFile, ShadowUploadResponse},
+};
+
+/// Codec used to transparently (de)compress an object's bytes around upload and
+/// download.
+///
+/// Compressing before upload shrinks the billable storage footprint of
+/// compressible payloads (logs, JSON, CSV). The codec is recorded in a small
+/// header prepended to the stored object (see [`compress`]), so objects stored
+/// without compression are recognized and passed through untouched on retrieval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionMode {
+    /// Store the bytes verbatim, without a header.
+    None,
+    /// Compress with the LZ4 block format.
+    Lz4,
+    /// Compress with the Zstd frame format at the given level.
+    Zstd {
+        /// Zstd compression level, typically `1..=22`.
+        level: i32,
+    },
+}
+
+/// The four magic bytes prepended to every compressed object: `b"SDC1"`.
+pub const COMPRESSION_MAGIC: [u8; 4] = *b"SDC1";
+
+const CODEC_LZ4: u8 = 1;
+const CODEC_ZSTD: u8 = 2;
+
+/// Length of the header prepended to compressed objects: a 4-byte magic, a
+/// 1-byte codec id, and a 4-byte little-endian original length.
+const HEADER_LEN: usize = 9;
+
+/// Compresses `bytes` according to `mode`, prepending the recognition header.
+///
+/// For [`CompressionMode::None`] the bytes are returned verbatim (no header), so
+/// disabling compression produces byte-for-byte the same object as before this
+/// feature existed.
+pub(crate) fn compress(mode: CompressionMode, bytes: &[u8]) -> ShadowDriveResult<Vec<u8>> {
+    let (codec, payload) = match mode {
+        CompressionMode::None => return Ok(bytes.to_vec()),
+        CompressionMode::Lz4 => (CODEC_LZ4, lz4_flex::compress(bytes)),
+        CompressionMode::Zstd { level } => (
+            CODEC_ZSTD,
+            zstd::stream::encode_all(bytes, level).map_err(Error::Compression)?,
+        ),
+    };
+
+    let original_len: u32 = bytes.len().try_into().map_err(|_| Error::InvalidStorage)?;
+
+    let mut out = Vec::with_capacity(HEADER_LEN + payload.len());
+    out.extend_from_slice(&COMPRESSION_MAGIC);
+    out.push(codec);
+    out.extend_from_slice(&original_len.to_le_bytes());
+    out.extend_from_slice(&payload);
+    Ok(out)
+}
+
+/// Reverses [`compress`]. Bytes that don't start with [`COMPRESSION_MAGIC`] are
+/// assumed to be an uncompressed (or legacy) object and returned untouched.
+pub(crate) fn decompress(bytes: &[u8]) -> ShadowDriveResult<Vec<u8>> {
+    if bytes.len() < HEADER_LEN || bytes[..COMPRESSION_MAGIC.len()] != COMPRESSION_MAGIC {
+        return Ok(bytes.to_vec());
+    }
+
+    let codec = bytes[4];
+    let original_len = u32::from_le_bytes([bytes[5], bytes[6], bytes[7], bytes[8]]) as usize;
+    let payload = &bytes[HEADER_LEN..];
+
+    match codec {
+        CODEC_LZ4 => lz4_flex::decompress(payload, original_len)
+            .map_err(|error| Error::Decompression(format!("{:?}", error))),
+        CODEC_ZSTD => zstd::stream::decode_all(payload)
+            .map_err(|error| Error::Decompression(format!("{:?}", error))),
+        other => Err(Error::UnknownCompressionCodec(other)),
+    }
+}
+
+/// Returns a copy of `file` whose bytes have been compressed according to
+/// `mode`, preserving its name. Works for both in-memory and file-path backed
+/// [`ShadowFile`]s, since it reads the file's bytes before compressing.
+async fn compress_shadow_file(
+    file: ShadowFile,
+    mode: CompressionMode,
+) -> ShadowDriveResult<ShadowFile> {
+    if let CompressionMode::None = mode {
+        return Ok(file);
+    }
+    let compressed = compress(mode, &file.read().await?)?;
+    Ok(ShadowFile::bytes(file.name, compressed))
+}
+
+impl<T> ShadowDriveClient<T>
+where
+    T: Signer + Send + Sync,
+{
+    /// Uploads `files` to the given [`StorageAccount`](crate::models::StorageAccount),
+    /// compressing each one client-side according to `mode` before it is handed to
+    /// [`store_files`](Self::store_files).
+    ///
+    /// Each object is stored with a recognition header so that
+    /// [`download_object`](Self::download_object) can decompress it transparently
+    /// on retrieval. Note that [`get_object_data`](Self::get_object_data) returns only
+    /// the object's metadata, not its contents; callers retrieving compressed objects
+    /// should use [`download_object`](Self::download_object). Pass [`CompressionMode::None`] to
+    /// store the bytes verbatim,
+    /// which produces byte-for-byte the same objects as a plain
+    /// [`store_files`](Self::store_files) call.
+    pub async fn store_files_compressed(
+        &self,
+        storage_account_key: &Pubkey,
+        files: Vec<ShadowFile>,
+        mode: CompressionMode,
+    ) -> ShadowDriveResult<ShadowUploadResponse> {
+        let mut compressed = Vec::with_capacity(files.len());
+        for file in files {
+            compressed.push(compress_shadow_file(file, mode).await?);
+        }
+        self.store_files(storage_account_key, compressed).await
+    }
+
+    /// Downloads the object at `location`, transparently decompressing it when it
+    /// was stored via [`store_files_compressed`](Self::store_files_compressed).
+    ///
+    /// Objects stored without compression don't carry the recognition header and
+    /// are returned byte-for-byte, preserving backward compatibility with files
+    /// uploaded before this feature existed.
+    pub async fn download_object(&self, location: &str) -> ShadowDriveResult<Vec<u8>> {
+        let response = self.http_client.get(location).send().await?;
+
+        if !response.status().is_success() {
+            return Err(Error::ShadowDriveServerError {
+                status: response.status().as_u16(),
+                message: response.json::<Value>().await?,
+            });
+        }
+
+        let bytes = response.bytes().await?;
+        decompress(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &[u8] =
+        b"the quick brown fox jumps over the lazy dog, over and over and over again";
+
+    #[test]
+    fn uncompressed_bytes_pass_through() {
+        // `None` stores bytes verbatim, and bytes without the magic decompress untouched.
+        assert_eq!(compress(CompressionMode::None, SAMPLE).unwrap(), SAMPLE);
+        assert_eq!(decompress(SAMPLE).unwrap(), SAMPLE);
+    }
+
+    #[test]
+    fn lz4_round_trip() {
+        let compressed = compress(CompressionMode::Lz4, SAMPLE).unwrap();
+        assert_eq!(&compressed[..COMPRESSION_MAGIC.len()], &COMPRESSION_MAGIC);
+        assert_eq!(decompress(&compressed).unwrap(), SAMPLE);
+    }
+
+    #[test]
+    fn zstd_round_trip() {
+        let compressed = compress(CompressionMode::Zstd { level: 3 }, SAMPLE).unwrap();
+        assert_eq!(&compressed[..COMPRESSION_MAGIC.len()], &COMPRESSION_MAGIC);
+        assert_eq!(decompress(&compressed).unwrap(), SAMPLE);
+    }
+
+    #[test]
+    fn unknown_codec_is_rejected() {
+        let mut object = Vec::new();
+        object.extend_from_slice(&COMPRESSION_MAGIC);
+        object.push(0xff);
+        object.extend_from_slice(&(SAMPLE.len() as u32).to_le_bytes());
+        object.extend_from_slice(SAMPLE);
+
+        assert!(matches!(
+            decompress(&object),
+            Err(Error::UnknownCompressionCodec(0xff))
+        ));
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use futures::stream::{self, StreamExt};
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+use tracing::Instrument;
+
+use super::ShadowDriveClient;
+use crate::models::{ShadowDriveResult, ShadowFile, ShadowUploadResponse};
+
+/// Number of files [`store_files_batch`](ShadowDriveClient::store_files_batch) uploads
+/// concurrently when the caller doesn't specify its own limit.
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 8;
+
+/// The outcome of uploading a single [`ShadowFile`] as part of a batch.
+///
+/// A batch never aborts on the first failure; each file's result is collected
+/// here instead so callers can inspect, retry, or report the individual
+/// failures without losing the successes.
+pub struct BatchUploadResult {
+    /// The name of the file this result corresponds to.
+    pub name: String,
+    /// The result of the upload, mirroring the return of
+    /// [`store_files`](ShadowDriveClient::store_files).
+    pub result: ShadowDriveResult<ShadowUploadResponse>,
+}
+
+impl<T> ShadowDriveClient<T>
+where
+    T: Signer + Send + Sync,
+{
+    /// Uploads a collection of [`ShadowFile`]s to the given
+    /// [`StorageAccount`](crate::models::StorageAccount) with bounded concurrency.
+    /// * `storage_account_key` - The public key of the [`StorageAccount`](crate::models::StorageAccount).
+    /// * `files` - The files to upload.
+    /// * `max_concurrent` - The maximum number of uploads in flight at once.
+    /// A value of `0` is treated as `1`; [`DEFAULT_BATCH_CONCURRENCY`] is a sensible default.
+    ///
+    /// Each file is uploaded through the same signed-transaction + `send-storage-request`
+    /// path as [`store_files`](ShadowDriveClient::store_files), so a single failing file
+    /// leaves the rest of the batch untouched. The returned vector contains one
+    /// [`BatchUploadResult`] per input file, in completion order.
+    ///
+    /// Progress (`completed` / `total`) is emitted on the `store_files_batch`
+    /// [`tracing`] span so callers uploading hundreds of files get feedback.
+    pub async fn store_files_batch(
+        &self,
+        storage_account_key: &Pubkey,
+        files: Vec<ShadowFile>,
+        max_concurrent: usize,
+    ) -> Vec<BatchUploadResult> {
+        let max_concurrent = max_concurrent.max(1);
+        let total = files.len();
+        let completed = Arc::new(AtomicUsize::new(0));
+        let span = tracing::info_span!("store_files_batch", total, max_concurrent);
+
+        let uploads = stream::iter(files)
+            .map(|file| {
+                let completed = Arc::clone(&completed);
+                async move {
+                    let name = file.name.clone();
+                    let result = self.store_files(storage_account_key, vec![file]).await;
+                    let done = completed.fetch_add(1, Ordering::SeqCst) + 1;
+                    tracing::info!(completed = done, total, name = %name, "batch upload progress");
+                    BatchUploadResult { name, result }
+                }
+            })
+            .buffer_unordered(max_concurrent)
+            .collect::<Vec<_>>();
+
+        uploads.instrument(span).await
+    }
+}
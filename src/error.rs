@@ -0,0 +1,66 @@
+use serde_json::Value;
+use thiserror::Error;
+
+/// Errors that can occur while interacting with the Shadow Drive.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// An error returned by the Shadow Drive HTTP API, carrying the HTTP status
+    /// and the server-provided message body.
+    #[error("shadow drive server returned {status}: {message}")]
+    ShadowDriveServerError {
+        /// The HTTP status code returned by the server.
+        status: u16,
+        /// The JSON error body returned by the server.
+        message: Value,
+    },
+
+    /// A transaction failed to serialize prior to being sent.
+    #[error("failed to serialize transaction: {0}")]
+    TransactionSerializationFailed(String),
+
+    /// The requested storage size could not be represented.
+    #[error("invalid storage size")]
+    InvalidStorage,
+
+    /// The signer's `UserInfo` account has not been initialized yet.
+    #[error("user info account has not been created")]
+    UserInfoNotCreated,
+
+    /// An operation that requires an immutable storage account was attempted on
+    /// a mutable one.
+    #[error("storage account is not immutable")]
+    StorageAccountIsNotImmutable,
+
+    /// An object's bytes could not be compressed with the selected codec.
+    #[error("compression error: {0}")]
+    Compression(#[source] std::io::Error),
+
+    /// A compressed object's payload could not be decompressed.
+    #[error("decompression error: {0}")]
+    Decompression(String),
+
+    /// A stored object carried a compression header with a codec id this client
+    /// does not recognize.
+    #[error("unknown compression codec id: {0}")]
+    UnknownCompressionCodec(u8),
+
+    /// GenesysGo authentication failed while acquiring or refreshing a bearer token.
+    #[error("authentication failed: {0}")]
+    AuthenticationFailed(String),
+
+    /// A request or response body could not be (de)serialized as JSON.
+    #[error("invalid json: {0}")]
+    InvalidJson(#[source] serde_json::Error),
+
+    /// An error returned by the underlying HTTP client.
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+
+    /// An error returned by the Solana RPC client.
+    #[error(transparent)]
+    SolanaClient(#[from] solana_client::client_error::ClientError),
+
+    /// A transaction could not be signed.
+    #[error(transparent)]
+    Signing(#[from] solana_sdk::signature::SignerError),
+}